@@ -38,6 +38,73 @@
 //! ```
 //! Pre-compute arguments to be fixed in a local variable, if their creation is expensive or has unwanted side-effects.
 //!
+//! Numbered placeholders `_0`, `_1`, ... (up to `_9`) forward from the closure too, but let you
+//! reorder or duplicate the incoming arguments instead of just forwarding them in order.
+//! ```rust
+//! # #[macro_use]
+//! # extern crate partial_application;
+//! # fn main() {
+//! #
+//! fn foo(a: i32, b: i32) -> i32 {
+//!     a - b
+//! }
+//!
+//! let flipped = partial!(foo => _1, _0);
+//! assert_eq!(flipped(2, 10), foo(10, 2));
+//! # }
+//! ```
+//! The indices used must form a contiguous range starting at `_0`, and plain `_` cannot be mixed
+//! with numbered placeholders in the same call.
+//!
+//! Alternatively, prefix the expression with `=` to have it evaluated exactly once, at the
+//! time `partial!` is called, instead of on every call of the resulting closure.
+//! ```rust
+//! # #[macro_use]
+//! # extern crate partial_application;
+//! # fn main() {
+//! #
+//! fn identity(x: u32) -> u32 { x }
+//!
+//! let mut n = 0;
+//! let f = partial!(identity => ={ n += 1; n });
+//! assert_eq!(f(), 1);
+//! assert_eq!(f(), 1);
+//! # }
+//! ```
+//!
+//! A method can be partially applied against a concrete receiver by writing
+//! `receiver.method` in place of the function, instead of going through the
+//! fully qualified `Type::method` form with the receiver as the first argument.
+//! ```rust
+//! # #[macro_use]
+//! # extern crate partial_application;
+//! # fn main() {
+//! #
+//! let v = vec![1, 2, 3];
+//! let get = partial!(v.get => _);
+//! assert_eq!(get(1), Some(&2));
+//! # }
+//! ```
+//! The receiver must be a single token (an identifier or a parenthesized expression).
+//!
+//! A `_` placeholder can carry a type annotation, `_: ty`, which is attached to its
+//! closure parameter. This helps inference in generic or ambiguous contexts, like
+//! passing the closure straight into a collection adapter.
+//! ```rust
+//! # #[macro_use]
+//! # extern crate partial_application;
+//! # fn main() {
+//! #
+//! fn add(a: i32, b: i32) -> i32 {
+//!     a + b
+//! }
+//!
+//! let v: Vec<i32> = vec![1, 2, 3];
+//! let added: Vec<i32> = v.into_iter().map(partial!(add => _: i32, 10)).collect();
+//! assert_eq!(added, vec![11, 12, 13]);
+//! # }
+//! ```
+//!
 //! You can also use a comma (`,`) or semicolon (`;`) instead of the arrow (`=>`).
 //! This strange syntax choice is due to limitations imposed on us by the macro system.
 //! No other tokens may follow the expression token for the function.
@@ -50,89 +117,387 @@
 ///
 /// Function arguments are either expressions or `_` <br>
 /// `_` arguments have to be supplied on each call. They forward from the resulting closure into the function. <br>
-/// Expressions are hardcoded into the function call. <br>
+/// Expressions are hardcoded into the function call and, by default, reevaluated on every call. <br>
 /// `partial!(foo => _)` => `|a| foo(a);` <br>
 /// `partial!(foo => 2)` => `|| foo(2);`
 ///
+/// Prefixing an expression with `=` evaluates it exactly once, when `partial!` itself runs,
+/// and captures the resulting value by move. <br>
+/// `partial!(foo => =expensive())` => `{ let p = expensive(); move || foo(p) };`
+///
+/// Numbered placeholders `_0` through `_9` forward from the closure like `_`, but by index,
+/// allowing reordering and duplication of the forwarded arguments. <br>
+/// `partial!(foo => _1, _0)` => `|a0, a1| foo(a1, a0);` <br>
+/// `partial!(foo => _0, _0)` => `|a0| foo(a0, a0);` (requires the forwarded type to be `Copy`;
+/// the argument is passed by value each time it appears, it is not cloned) <br>
+/// The indices used must be contiguous starting at 0, and cannot be mixed with plain `_` in the
+/// same invocation.
+///
+/// A method can be partially applied against its receiver directly: <br>
+/// `partial!(recv.method => _)` => `|a| recv.method(a);` <br>
+/// The receiver must be a single token (an identifier or a parenthesized expression), e.g.
+/// `(a + b).method => _` works, but `a + b.method => _` does not.
+///
+/// A `_` placeholder can be annotated with a type, which carries over to its closure
+/// parameter: <br>
+/// `partial!(foo => _: i32, _)` => `|a: i32, b| foo(a, b);` <br>
+/// Only the placeholders that are annotated get a type; the rest are left for inference
+/// as before.
+///
 /// Prepending `move` to the `fn_name` creates a move closure. Trailing commas are permitted.
 #[macro_export]
 macro_rules! partial {
-    // The macro works with 3 lists
+    // The macro works with 5 lists
     // 1. closure args $cl_arg(s)
-    //    The argument identifiers for the closure
+    //    `(ident)` or `(ident : ty)` entries for the closure parameter list, one
+    //    per sequential `_` / `_: ty` placeholder. Numbered placeholders build their
+    //    closure args from `slots` instead (see below) and leave this list empty;
+    //    they don't support type annotations.
     // 2. fn args      $fn_arg(s)
     //    The argument identifiers and forwarded expressions for the fn
     //
     //    Arg idents are passed around for hygiene reasons and to keep track
     //    of their number
     //
-    // 3. the macro arguments $m_args
-    //    A list of expressions and the forwarding sign '_'
-    //    from which the former two lists are built up
+    // 3. eager bindings $eager(s)
+    //    Comma separated `ident : expr` pairs for `=expr` arguments, which are evaluated once
+    //    up front instead of being forwarded or hardcoded inline. The ident half
+    //    also shows up as the corresponding entry in fn_args.
+    //
+    // 4. numbered placeholder slots $slots
+    //    A fixed-size tuple of 10 slots (one per digit `_0`..=`_9`), each either
+    //    `()` (index unused) or `($ident)` holding the `_N` token last seen for
+    //    that index. Every occurrence pushes its own `_N` token into fn_args;
+    //    since they are the same identifier, `_0, _0` refer to one argument.
+    //
+    // 5. the macro arguments $m_args
+    //    A list of expressions, the forwarding sign '_', numbered placeholders
+    //    '_0'..'_9' and eager expressions '=expr' from which the former lists
+    //    are built up
     //
     // Until $m_args is empty, an element is popped off its front
-    // and the appropiate pieces are pushed to cl_args and/or fn_args
+    // and the appropiate pieces are pushed to cl_args, fn_args, eager and/or slots
     //
-    // The fn ident and the move closure "boolean" (either "move" or "()")
-    // are simpyl passed through during list processing inside $pt (pass-through)
+    // The move closure "boolean" (either "move" or "()") and the callable itself
+    // are simpyl passed through during list processing inside $pt (pass-through).
+    // The callable is tagged `(Id $id:expr)` for a plain function/closure expression,
+    // or `(Method ($recv:tt) $method:ident)` for `recv.method` call syntax, since
+    // capturing a receiver and method name as raw tokens is what lets the terminal
+    // arms below emit an actual method call instead of calling an opaque expr.
 
     // exhausted macro arguments, create closure
-    (@inner [(() $id:expr) ($($cl_arg:ident),*) ($($fn_arg:expr),*)] ()) => {
-        |$($cl_arg),*| $id($($fn_arg),*);
+    (@inner [(() (Id $id:expr)) ($(($cl_arg:ident $(: $cl_ty:ty)?)),*) ($($fn_arg:expr),*) () (() () () () () () () () () ())] ()) => {
+        |$($cl_arg $(: $cl_ty)?),*| $id($($fn_arg),*);
+    };
+    (@inner [(() (Method ($recv:tt) $method:ident)) ($(($cl_arg:ident $(: $cl_ty:ty)?)),*) ($($fn_arg:expr),*) () (() () () () () () () () () ())] ()) => {
+        |$($cl_arg $(: $cl_ty)?),*| $recv.$method($($fn_arg),*);
     };
     // with move
-    (@inner [(move $id:expr) ($($cl_arg:ident),*) ($($fn_arg:expr),*)] ()) => {
-        move |$($cl_arg),*| $id($($fn_arg),*);
+    (@inner [(move (Id $id:expr)) ($(($cl_arg:ident $(: $cl_ty:ty)?)),*) ($($fn_arg:expr),*) () (() () () () () () () () () ())] ()) => {
+        move |$($cl_arg $(: $cl_ty)?),*| $id($($fn_arg),*);
+    };
+    (@inner [(move (Method ($recv:tt) $method:ident)) ($(($cl_arg:ident $(: $cl_ty:ty)?)),*) ($($fn_arg:expr),*) () (() () () () () () () () () ())] ()) => {
+        move |$($cl_arg $(: $cl_ty)?),*| $recv.$method($($fn_arg),*);
+    };
+    // with eager bindings, present regardless of move/no-move: the bindings are
+    // owned locals, so capturing them always requires a move closure
+    (@inner [(() (Id $id:expr)) ($(($cl_arg:ident $(: $cl_ty:ty)?)),*) ($($fn_arg:expr),*) ($eager_id:ident : $eager_expr:expr $(, $more_id:ident : $more_expr:expr)*) (() () () () () () () () () ())] ()) => {
+        {
+            let $eager_id = $eager_expr;
+            $(let $more_id = $more_expr;)*
+            move |$($cl_arg $(: $cl_ty)?),*| $id($($fn_arg),*)
+        };
+    };
+    (@inner [(() (Method ($recv:tt) $method:ident)) ($(($cl_arg:ident $(: $cl_ty:ty)?)),*) ($($fn_arg:expr),*) ($eager_id:ident : $eager_expr:expr $(, $more_id:ident : $more_expr:expr)*) (() () () () () () () () () ())] ()) => {
+        {
+            let $eager_id = $eager_expr;
+            $(let $more_id = $more_expr;)*
+            move |$($cl_arg $(: $cl_ty)?),*| $recv.$method($($fn_arg),*)
+        };
+    };
+    (@inner [(move (Id $id:expr)) ($(($cl_arg:ident $(: $cl_ty:ty)?)),*) ($($fn_arg:expr),*) ($eager_id:ident : $eager_expr:expr $(, $more_id:ident : $more_expr:expr)*) (() () () () () () () () () ())] ()) => {
+        {
+            let $eager_id = $eager_expr;
+            $(let $more_id = $more_expr;)*
+            move |$($cl_arg $(: $cl_ty)?),*| $id($($fn_arg),*)
+        };
+    };
+    (@inner [(move (Method ($recv:tt) $method:ident)) ($(($cl_arg:ident $(: $cl_ty:ty)?)),*) ($($fn_arg:expr),*) ($eager_id:ident : $eager_expr:expr $(, $more_id:ident : $more_expr:expr)*) (() () () () () () () () () ())] ()) => {
+        {
+            let $eager_id = $eager_expr;
+            $(let $more_id = $more_expr;)*
+            move |$($cl_arg $(: $cl_ty)?),*| $recv.$method($($fn_arg),*)
+        };
+    };
+    // numbered placeholders were used: validate them and derive cl_args from slots,
+    // then feed the result back into the arms above
+    (@inner [$pt:tt $cl_args:tt ($($fn_arg:expr),*) $eager:tt ($s0:tt $s1:tt $s2:tt $s3:tt $s4:tt $s5:tt $s6:tt $s7:tt $s8:tt $s9:tt)] ()) => {
+        partial!(@numbered_params [$pt ($($fn_arg),*) $eager] ($s0 $s1 $s2 $s3 $s4 $s5 $s6 $s7 $s8 $s9))
     };
 
-    // process forwarder '_' ,
-    (@inner [$pt:tt ($($cl_arg:ident),*) ($($fn_arg:expr),*)] (_ , $($m_arg:tt)*) ) => {
+    // process forwarder '_' , -- only valid if no numbered placeholder was used yet
+    (@inner [$pt:tt ($(($cl_arg:ident $(: $cl_ty:ty)?)),*) ($($fn_arg:expr),*) $eager:tt (() () () () () () () () () ())] (_ , $($m_arg:tt)*) ) => {
         partial!(
-            @inner [$pt ($($cl_arg,)* a) ($($fn_arg,)* a)] ($($m_arg)*)
+            @inner [$pt ($(($cl_arg $(: $cl_ty)?),)* (a)) ($($fn_arg,)* a) $eager (() () () () () () () () () ())] ($($m_arg)*)
         )
     };
     // last forwarder (if no trailing comma)
-    (@inner [$pt:tt ($($cl_arg:ident),*) ($($fn_arg:expr),*)] (_) ) => {
+    (@inner [$pt:tt ($(($cl_arg:ident $(: $cl_ty:ty)?)),*) ($($fn_arg:expr),*) $eager:tt (() () () () () () () () () ())] (_) ) => {
+        partial!(
+            @inner [$pt ($(($cl_arg $(: $cl_ty)?),)* (a)) ($($fn_arg,)* a) $eager (() () () () () () () () () ())] ()
+        )
+    };
+    // typed forwarder '_: ty' , -- annotates the parameter the placeholder forwards to
+    (@inner [$pt:tt ($(($cl_arg:ident $(: $cl_ty:ty)?)),*) ($($fn_arg:expr),*) $eager:tt (() () () () () () () () () ())] (_ : $ty:ty , $($m_arg:tt)*) ) => {
+        partial!(
+            @inner [$pt ($(($cl_arg $(: $cl_ty)?),)* (a : $ty)) ($($fn_arg,)* a) $eager (() () () () () () () () () ())] ($($m_arg)*)
+        )
+    };
+    // last typed forwarder (if no trailing comma)
+    (@inner [$pt:tt ($(($cl_arg:ident $(: $cl_ty:ty)?)),*) ($($fn_arg:expr),*) $eager:tt (() () () () () () () () () ())] (_ : $ty:ty) ) => {
         partial!(
-            @inner [$pt ($($cl_arg,)* a) ($($fn_arg,)* a)] ()
+            @inner [$pt ($(($cl_arg $(: $cl_ty)?),)* (a : $ty)) ($($fn_arg,)* a) $eager (() () () () () () () () () ())] ()
+        )
+    };
+    // sequential '_' / '_: ty' used together with a numbered placeholder - not allowed
+    (@inner [$pt:tt $cl_args:tt $fn_args:tt $eager:tt $slots:tt] (_ , $($m_arg:tt)*) ) => {
+        compile_error!("partial!: cannot mix sequential `_` placeholders with numbered `_N` placeholders")
+    };
+    (@inner [$pt:tt $cl_args:tt $fn_args:tt $eager:tt $slots:tt] (_) ) => {
+        compile_error!("partial!: cannot mix sequential `_` placeholders with numbered `_N` placeholders")
+    };
+    (@inner [$pt:tt $cl_args:tt $fn_args:tt $eager:tt $slots:tt] (_ : $ty:ty , $($m_arg:tt)*) ) => {
+        compile_error!("partial!: cannot mix sequential `_` placeholders with numbered `_N` placeholders")
+    };
+    (@inner [$pt:tt $cl_args:tt $fn_args:tt $eager:tt $slots:tt] (_ : $ty:ty) ) => {
+        compile_error!("partial!: cannot mix sequential `_` placeholders with numbered `_N` placeholders")
+    };
+
+    // a numbered placeholder can't carry a type annotation: `_: ty` supports it
+    // because it feeds a single, statically known closure parameter, but `_N`
+    // may be reused several times and its parameter type is derived once from
+    // all of its occurrences, so there is no single place to hang the `: ty` on
+    (@inner [$pt:tt $cl_args:tt $fn_args:tt $eager:tt $slots:tt] (_0 : $ty:ty $(, $($m_arg:tt)*)?) ) => {
+        compile_error!("partial!: numbered placeholders `_N` cannot carry a type annotation")
+    };
+    (@inner [$pt:tt $cl_args:tt $fn_args:tt $eager:tt $slots:tt] (_1 : $ty:ty $(, $($m_arg:tt)*)?) ) => {
+        compile_error!("partial!: numbered placeholders `_N` cannot carry a type annotation")
+    };
+    (@inner [$pt:tt $cl_args:tt $fn_args:tt $eager:tt $slots:tt] (_2 : $ty:ty $(, $($m_arg:tt)*)?) ) => {
+        compile_error!("partial!: numbered placeholders `_N` cannot carry a type annotation")
+    };
+    (@inner [$pt:tt $cl_args:tt $fn_args:tt $eager:tt $slots:tt] (_3 : $ty:ty $(, $($m_arg:tt)*)?) ) => {
+        compile_error!("partial!: numbered placeholders `_N` cannot carry a type annotation")
+    };
+    (@inner [$pt:tt $cl_args:tt $fn_args:tt $eager:tt $slots:tt] (_4 : $ty:ty $(, $($m_arg:tt)*)?) ) => {
+        compile_error!("partial!: numbered placeholders `_N` cannot carry a type annotation")
+    };
+    (@inner [$pt:tt $cl_args:tt $fn_args:tt $eager:tt $slots:tt] (_5 : $ty:ty $(, $($m_arg:tt)*)?) ) => {
+        compile_error!("partial!: numbered placeholders `_N` cannot carry a type annotation")
+    };
+    (@inner [$pt:tt $cl_args:tt $fn_args:tt $eager:tt $slots:tt] (_6 : $ty:ty $(, $($m_arg:tt)*)?) ) => {
+        compile_error!("partial!: numbered placeholders `_N` cannot carry a type annotation")
+    };
+    (@inner [$pt:tt $cl_args:tt $fn_args:tt $eager:tt $slots:tt] (_7 : $ty:ty $(, $($m_arg:tt)*)?) ) => {
+        compile_error!("partial!: numbered placeholders `_N` cannot carry a type annotation")
+    };
+    (@inner [$pt:tt $cl_args:tt $fn_args:tt $eager:tt $slots:tt] (_8 : $ty:ty $(, $($m_arg:tt)*)?) ) => {
+        compile_error!("partial!: numbered placeholders `_N` cannot carry a type annotation")
+    };
+    (@inner [$pt:tt $cl_args:tt $fn_args:tt $eager:tt $slots:tt] (_9 : $ty:ty $(, $($m_arg:tt)*)?) ) => {
+        compile_error!("partial!: numbered placeholders `_N` cannot carry a type annotation")
+    };
+
+    // bare identifier argument: could be a numbered placeholder `_0`..`_9` or a
+    // plain variable used as an expression, `@classify_num` tells them apart
+    (@inner [$pt:tt $cl_args:tt ($($fn_arg:expr),*) $eager:tt $slots:tt] ($ph:ident , $($m_arg:tt)*) ) => {
+        partial!(@classify_num $ph $ph [$pt $cl_args ($($fn_arg),*) $eager $slots ($($m_arg)*)])
+    };
+    (@inner [$pt:tt $cl_args:tt ($($fn_arg:expr),*) $eager:tt $slots:tt] ($ph:ident) ) => {
+        partial!(@classify_num $ph $ph [$pt $cl_args ($($fn_arg),*) $eager $slots ()])
+    };
+
+    // dispatch a bare identifier argument: decide whether it is a reserved
+    // numbered placeholder `_0`..`_9` or an ordinary expression
+    // mixing numbered placeholders with sequential `_` is rejected here
+    (@classify_num _0 $keep:ident [$pt:tt ($(($cl_arg:ident $(: $cl_ty:ty)?)),+) ($($fn_arg:expr),*) $eager:tt $slots:tt ($($rest:tt)*)] ) => {
+        compile_error!("partial!: cannot mix sequential `_` placeholders with numbered `_N` placeholders")
+    };
+    (@classify_num _1 $keep:ident [$pt:tt ($(($cl_arg:ident $(: $cl_ty:ty)?)),+) ($($fn_arg:expr),*) $eager:tt $slots:tt ($($rest:tt)*)] ) => {
+        compile_error!("partial!: cannot mix sequential `_` placeholders with numbered `_N` placeholders")
+    };
+    (@classify_num _2 $keep:ident [$pt:tt ($(($cl_arg:ident $(: $cl_ty:ty)?)),+) ($($fn_arg:expr),*) $eager:tt $slots:tt ($($rest:tt)*)] ) => {
+        compile_error!("partial!: cannot mix sequential `_` placeholders with numbered `_N` placeholders")
+    };
+    (@classify_num _3 $keep:ident [$pt:tt ($(($cl_arg:ident $(: $cl_ty:ty)?)),+) ($($fn_arg:expr),*) $eager:tt $slots:tt ($($rest:tt)*)] ) => {
+        compile_error!("partial!: cannot mix sequential `_` placeholders with numbered `_N` placeholders")
+    };
+    (@classify_num _4 $keep:ident [$pt:tt ($(($cl_arg:ident $(: $cl_ty:ty)?)),+) ($($fn_arg:expr),*) $eager:tt $slots:tt ($($rest:tt)*)] ) => {
+        compile_error!("partial!: cannot mix sequential `_` placeholders with numbered `_N` placeholders")
+    };
+    (@classify_num _5 $keep:ident [$pt:tt ($(($cl_arg:ident $(: $cl_ty:ty)?)),+) ($($fn_arg:expr),*) $eager:tt $slots:tt ($($rest:tt)*)] ) => {
+        compile_error!("partial!: cannot mix sequential `_` placeholders with numbered `_N` placeholders")
+    };
+    (@classify_num _6 $keep:ident [$pt:tt ($(($cl_arg:ident $(: $cl_ty:ty)?)),+) ($($fn_arg:expr),*) $eager:tt $slots:tt ($($rest:tt)*)] ) => {
+        compile_error!("partial!: cannot mix sequential `_` placeholders with numbered `_N` placeholders")
+    };
+    (@classify_num _7 $keep:ident [$pt:tt ($(($cl_arg:ident $(: $cl_ty:ty)?)),+) ($($fn_arg:expr),*) $eager:tt $slots:tt ($($rest:tt)*)] ) => {
+        compile_error!("partial!: cannot mix sequential `_` placeholders with numbered `_N` placeholders")
+    };
+    (@classify_num _8 $keep:ident [$pt:tt ($(($cl_arg:ident $(: $cl_ty:ty)?)),+) ($($fn_arg:expr),*) $eager:tt $slots:tt ($($rest:tt)*)] ) => {
+        compile_error!("partial!: cannot mix sequential `_` placeholders with numbered `_N` placeholders")
+    };
+    (@classify_num _9 $keep:ident [$pt:tt ($(($cl_arg:ident $(: $cl_ty:ty)?)),+) ($($fn_arg:expr),*) $eager:tt $slots:tt ($($rest:tt)*)] ) => {
+        compile_error!("partial!: cannot mix sequential `_` placeholders with numbered `_N` placeholders")
+    };
+    (@classify_num _0 $keep:ident [$pt:tt () ($($fn_arg:expr),*) $eager:tt ($old:tt $s1:tt $s2:tt $s3:tt $s4:tt $s5:tt $s6:tt $s7:tt $s8:tt $s9:tt) ($($rest:tt)*)] ) => {
+        partial!(@inner [$pt () ($($fn_arg,)* $keep) $eager (($keep) $s1 $s2 $s3 $s4 $s5 $s6 $s7 $s8 $s9)] ($($rest)*))
+    };
+    (@classify_num _1 $keep:ident [$pt:tt () ($($fn_arg:expr),*) $eager:tt ($s0:tt $old:tt $s2:tt $s3:tt $s4:tt $s5:tt $s6:tt $s7:tt $s8:tt $s9:tt) ($($rest:tt)*)] ) => {
+        partial!(@inner [$pt () ($($fn_arg,)* $keep) $eager ($s0 ($keep) $s2 $s3 $s4 $s5 $s6 $s7 $s8 $s9)] ($($rest)*))
+    };
+    (@classify_num _2 $keep:ident [$pt:tt () ($($fn_arg:expr),*) $eager:tt ($s0:tt $s1:tt $old:tt $s3:tt $s4:tt $s5:tt $s6:tt $s7:tt $s8:tt $s9:tt) ($($rest:tt)*)] ) => {
+        partial!(@inner [$pt () ($($fn_arg,)* $keep) $eager ($s0 $s1 ($keep) $s3 $s4 $s5 $s6 $s7 $s8 $s9)] ($($rest)*))
+    };
+    (@classify_num _3 $keep:ident [$pt:tt () ($($fn_arg:expr),*) $eager:tt ($s0:tt $s1:tt $s2:tt $old:tt $s4:tt $s5:tt $s6:tt $s7:tt $s8:tt $s9:tt) ($($rest:tt)*)] ) => {
+        partial!(@inner [$pt () ($($fn_arg,)* $keep) $eager ($s0 $s1 $s2 ($keep) $s4 $s5 $s6 $s7 $s8 $s9)] ($($rest)*))
+    };
+    (@classify_num _4 $keep:ident [$pt:tt () ($($fn_arg:expr),*) $eager:tt ($s0:tt $s1:tt $s2:tt $s3:tt $old:tt $s5:tt $s6:tt $s7:tt $s8:tt $s9:tt) ($($rest:tt)*)] ) => {
+        partial!(@inner [$pt () ($($fn_arg,)* $keep) $eager ($s0 $s1 $s2 $s3 ($keep) $s5 $s6 $s7 $s8 $s9)] ($($rest)*))
+    };
+    (@classify_num _5 $keep:ident [$pt:tt () ($($fn_arg:expr),*) $eager:tt ($s0:tt $s1:tt $s2:tt $s3:tt $s4:tt $old:tt $s6:tt $s7:tt $s8:tt $s9:tt) ($($rest:tt)*)] ) => {
+        partial!(@inner [$pt () ($($fn_arg,)* $keep) $eager ($s0 $s1 $s2 $s3 $s4 ($keep) $s6 $s7 $s8 $s9)] ($($rest)*))
+    };
+    (@classify_num _6 $keep:ident [$pt:tt () ($($fn_arg:expr),*) $eager:tt ($s0:tt $s1:tt $s2:tt $s3:tt $s4:tt $s5:tt $old:tt $s7:tt $s8:tt $s9:tt) ($($rest:tt)*)] ) => {
+        partial!(@inner [$pt () ($($fn_arg,)* $keep) $eager ($s0 $s1 $s2 $s3 $s4 $s5 ($keep) $s7 $s8 $s9)] ($($rest)*))
+    };
+    (@classify_num _7 $keep:ident [$pt:tt () ($($fn_arg:expr),*) $eager:tt ($s0:tt $s1:tt $s2:tt $s3:tt $s4:tt $s5:tt $s6:tt $old:tt $s8:tt $s9:tt) ($($rest:tt)*)] ) => {
+        partial!(@inner [$pt () ($($fn_arg,)* $keep) $eager ($s0 $s1 $s2 $s3 $s4 $s5 $s6 ($keep) $s8 $s9)] ($($rest)*))
+    };
+    (@classify_num _8 $keep:ident [$pt:tt () ($($fn_arg:expr),*) $eager:tt ($s0:tt $s1:tt $s2:tt $s3:tt $s4:tt $s5:tt $s6:tt $s7:tt $old:tt $s9:tt) ($($rest:tt)*)] ) => {
+        partial!(@inner [$pt () ($($fn_arg,)* $keep) $eager ($s0 $s1 $s2 $s3 $s4 $s5 $s6 $s7 ($keep) $s9)] ($($rest)*))
+    };
+    (@classify_num _9 $keep:ident [$pt:tt () ($($fn_arg:expr),*) $eager:tt ($s0:tt $s1:tt $s2:tt $s3:tt $s4:tt $s5:tt $s6:tt $s7:tt $s8:tt $old:tt) ($($rest:tt)*)] ) => {
+        partial!(@inner [$pt () ($($fn_arg,)* $keep) $eager ($s0 $s1 $s2 $s3 $s4 $s5 $s6 $s7 $s8 ($keep))] ($($rest)*))
+    };
+    // not a reserved placeholder: treat exactly like an ordinary expression argument
+    (@classify_num $other:tt $keep:ident [$pt:tt $cl_args:tt ($($fn_arg:expr),*) $eager:tt $slots:tt ($($rest:tt)*)] ) => {
+        partial!(@inner [$pt $cl_args ($($fn_arg,)* $keep) $eager $slots] ($($rest)*))
+    };
+
+    // validate that numbered placeholders form a contiguous 0..=N range and
+    // build the ascending closure parameter list from the captured tokens
+    (@numbered_params [$pt:tt ($($fn_arg:expr),*) $eager:tt] (($a0:ident) () () () () () () () () ())) => {
+        partial!(@inner [$pt (($a0)) ($($fn_arg),*) $eager (() () () () () () () () () ())] ())
+    };
+    (@numbered_params [$pt:tt ($($fn_arg:expr),*) $eager:tt] (($a0:ident) ($a1:ident) () () () () () () () ())) => {
+        partial!(@inner [$pt (($a0), ($a1)) ($($fn_arg),*) $eager (() () () () () () () () () ())] ())
+    };
+    (@numbered_params [$pt:tt ($($fn_arg:expr),*) $eager:tt] (($a0:ident) ($a1:ident) ($a2:ident) () () () () () () ())) => {
+        partial!(@inner [$pt (($a0), ($a1), ($a2)) ($($fn_arg),*) $eager (() () () () () () () () () ())] ())
+    };
+    (@numbered_params [$pt:tt ($($fn_arg:expr),*) $eager:tt] (($a0:ident) ($a1:ident) ($a2:ident) ($a3:ident) () () () () () ())) => {
+        partial!(@inner [$pt (($a0), ($a1), ($a2), ($a3)) ($($fn_arg),*) $eager (() () () () () () () () () ())] ())
+    };
+    (@numbered_params [$pt:tt ($($fn_arg:expr),*) $eager:tt] (($a0:ident) ($a1:ident) ($a2:ident) ($a3:ident) ($a4:ident) () () () () ())) => {
+        partial!(@inner [$pt (($a0), ($a1), ($a2), ($a3), ($a4)) ($($fn_arg),*) $eager (() () () () () () () () () ())] ())
+    };
+    (@numbered_params [$pt:tt ($($fn_arg:expr),*) $eager:tt] (($a0:ident) ($a1:ident) ($a2:ident) ($a3:ident) ($a4:ident) ($a5:ident) () () () ())) => {
+        partial!(@inner [$pt (($a0), ($a1), ($a2), ($a3), ($a4), ($a5)) ($($fn_arg),*) $eager (() () () () () () () () () ())] ())
+    };
+    (@numbered_params [$pt:tt ($($fn_arg:expr),*) $eager:tt] (($a0:ident) ($a1:ident) ($a2:ident) ($a3:ident) ($a4:ident) ($a5:ident) ($a6:ident) () () ())) => {
+        partial!(@inner [$pt (($a0), ($a1), ($a2), ($a3), ($a4), ($a5), ($a6)) ($($fn_arg),*) $eager (() () () () () () () () () ())] ())
+    };
+    (@numbered_params [$pt:tt ($($fn_arg:expr),*) $eager:tt] (($a0:ident) ($a1:ident) ($a2:ident) ($a3:ident) ($a4:ident) ($a5:ident) ($a6:ident) ($a7:ident) () ())) => {
+        partial!(@inner [$pt (($a0), ($a1), ($a2), ($a3), ($a4), ($a5), ($a6), ($a7)) ($($fn_arg),*) $eager (() () () () () () () () () ())] ())
+    };
+    (@numbered_params [$pt:tt ($($fn_arg:expr),*) $eager:tt] (($a0:ident) ($a1:ident) ($a2:ident) ($a3:ident) ($a4:ident) ($a5:ident) ($a6:ident) ($a7:ident) ($a8:ident) ())) => {
+        partial!(@inner [$pt (($a0), ($a1), ($a2), ($a3), ($a4), ($a5), ($a6), ($a7), ($a8)) ($($fn_arg),*) $eager (() () () () () () () () () ())] ())
+    };
+    (@numbered_params [$pt:tt ($($fn_arg:expr),*) $eager:tt] (($a0:ident) ($a1:ident) ($a2:ident) ($a3:ident) ($a4:ident) ($a5:ident) ($a6:ident) ($a7:ident) ($a8:ident) ($a9:ident))) => {
+        partial!(@inner [$pt (($a0), ($a1), ($a2), ($a3), ($a4), ($a5), ($a6), ($a7), ($a8), ($a9)) ($($fn_arg),*) $eager (() () () () () () () () () ())] ())
+    };
+    // any other shape has a gap in the indices, e.g. `_0, _2` without `_1`
+    (@numbered_params [$pt:tt $fn_args:tt $eager:tt] ($($slot:tt)*)) => {
+        compile_error!("partial!: numbered placeholders must form a contiguous range `_0..=_N` with no gaps")
+    };
+
+    // process eager '=expr' ,
+    // evaluated once up front; `p` is pushed into fn_args like a forwarder but
+    // bound via `let` in the eager list instead of being a closure parameter
+    (@inner [$pt:tt $cl_args:tt ($($fn_arg:expr),*) ($($eager_id:ident : $eager_expr:expr),*) $slots:tt] (= $e:expr , $($m_arg:tt)*) ) => {
+        partial!(
+            @inner [$pt $cl_args ($($fn_arg,)* p) ($($eager_id : $eager_expr,)* p : $e) $slots] ($($m_arg)*)
+        )
+    };
+    // last eager expr (if no trailing comma)
+    (@inner [$pt:tt $cl_args:tt ($($fn_arg:expr),*) ($($eager_id:ident : $eager_expr:expr),*) $slots:tt] (= $e:expr) ) => {
+        partial!(
+            @inner [$pt $cl_args ($($fn_arg,)* p) ($($eager_id : $eager_expr,)* p : $e) $slots] ()
         )
     };
 
     // process given expr
-    (@inner [$pt:tt $cl_args:tt ($($fn_arg:expr),*)] ($e:expr , $($m_arg:tt)*) ) => {
+    (@inner [$pt:tt $cl_args:tt ($($fn_arg:expr),*) $eager:tt $slots:tt] ($e:expr , $($m_arg:tt)*) ) => {
         partial!(
-            @inner [$pt $cl_args ($($fn_arg,)* $e)] ($($m_arg)*)
+            @inner [$pt $cl_args ($($fn_arg,)* $e) $eager $slots] ($($m_arg)*)
         )
     };
     // last expr (if no trailing comma)
-    (@inner [$pt:tt $cl_args:tt ($($fn_arg:expr),*)] ($e:expr) ) => {
+    (@inner [$pt:tt $cl_args:tt ($($fn_arg:expr),*) $eager:tt $slots:tt] ($e:expr) ) => {
         partial!(
-            @inner [$pt $cl_args ($($fn_arg,)* $e)] ()
+            @inner [$pt $cl_args ($($fn_arg,)* $e) $eager $slots] ()
         )
     };
 
     // entry points
     // ordered to match eagerly
+
+    // method-receiver call `recv.method`, checked before the plain `$id:expr` arms
+    // below: `$id:expr` would otherwise swallow `recv.method` whole as a field-access
+    // expression, and splicing that back in as `$id(args)` calls the *value* of
+    // `recv.method` instead of calling the method itself
+    // move
+    (move $recv:tt . $method:ident , $($args:tt)*) => {
+        partial!(@inner [(move (Method ($recv) $method)) () () () (() () () () () () () () () ())] ($($args)*))
+    };
+    (move $recv:tt . $method:ident ; $($args:tt)*) => {
+        partial!(@inner [(move (Method ($recv) $method)) () () () (() () () () () () () () () ())] ($($args)*))
+    };
+    (move $recv:tt . $method:ident => $($args:tt)*) => {
+        partial!(@inner [(move (Method ($recv) $method)) () () () (() () () () () () () () () ())] ($($args)*))
+    };
+    // no move
+    ($recv:tt . $method:ident , $($args:tt)*) => {
+        partial!(@inner [(() (Method ($recv) $method)) () () () (() () () () () () () () () ())] ($($args)*))
+    };
+    ($recv:tt . $method:ident ; $($args:tt)*) => {
+        partial!(@inner [(() (Method ($recv) $method)) () () () (() () () () () () () () () ())] ($($args)*))
+    };
+    ($recv:tt . $method:ident => $($args:tt)*) => {
+        partial!(@inner [(() (Method ($recv) $method)) () () () (() () () () () () () () () ())] ($($args)*))
+    };
+
     // move
     (move $id:expr , $($args:tt)*) => {
-        partial!(@inner [(move $id) () ()] ($($args)*))
+        partial!(@inner [(move (Id $id)) () () () (() () () () () () () () () ())] ($($args)*))
     };
     (move $id:expr ; $($args:tt)*) => {
-        partial!(@inner [(move $id) () ()] ($($args)*))
+        partial!(@inner [(move (Id $id)) () () () (() () () () () () () () () ())] ($($args)*))
     };
     (move $id:expr => $($args:tt)*) => {
-        partial!(@inner [(move $id) () ()] ($($args)*))
+        partial!(@inner [(move (Id $id)) () () () (() () () () () () () () () ())] ($($args)*))
     };
 
     // no move
     ($id:expr , $($args:tt)*) => {
-        partial!(@inner [(() $id) () ()] ($($args)*))
+        partial!(@inner [(() (Id $id)) () () () (() () () () () () () () () ())] ($($args)*))
     };
     ($id:expr ; $($args:tt)*) => {
-        partial!(@inner [(() $id) () ()] ($($args)*))
+        partial!(@inner [(() (Id $id)) () () () (() () () () () () () () () ())] ($($args)*))
     };
     ($id:expr => $($args:tt)*) => {
-        partial!(@inner [(() $id) () ()] ($($args)*))
+        partial!(@inner [(() (Id $id)) () () () (() () () () () () () () () ())] ($($args)*))
     };
 }
 
@@ -175,6 +540,26 @@ mod test {
         }
     }
 
+    #[test]
+    // eager arguments are evaluated exactly once, at closure-creation time
+    fn eager_capture() {
+        fn foo(a: u32, b: u32) -> u32 {
+            a + b
+        }
+
+        let mut calls = 0;
+        let mut next = || {
+            calls += 1;
+            calls
+        };
+
+        let f = partial!(foo => =next(), _);
+        assert_eq!(calls, 1);
+        assert_eq!(f(10), 11);
+        assert_eq!(f(10), 11);
+        assert_eq!(calls, 1);
+    }
+
     #[test]
     // tests preservation of argument order in a more complex setting
     fn interspersed_expr_and_forwarders() {
@@ -195,6 +580,84 @@ mod test {
         let reduced_foo = partial!(foo => true, _, _, true, true, _);
         assert_eq!(reduced_foo(false, false, false), 0b100110);
     }
+
+    #[test]
+    // numbered placeholders reorder and duplicate incoming arguments
+    fn numbered_placeholders() {
+        fn foo(a: u32, b: u32) -> u32 {
+            100 + a - b
+        }
+
+        let flipped = partial!(foo => _1, _0);
+        for i in 0..10 {
+            for j in 0..10 {
+                assert_eq!(foo(j, i), flipped(i, j));
+            }
+        }
+
+        let doubled = partial!(foo => _0, _0);
+        for i in 0..10 {
+            assert_eq!(doubled(i), 100);
+        }
+    }
+
+    #[test]
+    // methods can be partially applied directly against a receiver
+    fn method_receiver() {
+        struct Adder {
+            base: u32,
+        }
+        impl Adder {
+            fn add(&self, n: u32) -> u32 {
+                self.base + n
+            }
+        }
+
+        let adder = Adder { base: 10 };
+        let add5 = partial!(adder.add => 5);
+        assert_eq!(add5(), 15);
+
+        // move closure, receiver captured by value
+        fn make_adder() -> impl Fn(u32) -> u32 {
+            let adder = Adder { base: 20 };
+            partial!(move adder.add => _)
+        }
+        let f = make_adder();
+        assert_eq!(f(5), 25);
+
+        // a parenthesized expression is a single token and can be used as receiver
+        let doubled = partial!((adder.base * 2).checked_add => _);
+        assert_eq!(doubled(5), Some(25));
+    }
+
+    #[test]
+    // typed placeholders annotate only the parameters the user explicitly typed,
+    // which lets the closure's type be inferred in ambiguous contexts such as
+    // iterator adapters
+    fn typed_placeholders() {
+        fn foo(a: i32, b: i32, c: bool) -> i32 {
+            if c {
+                a + b
+            } else {
+                a - b
+            }
+        }
+
+        let f = partial!(foo => _: i32, 2, true);
+        assert_eq!(f(1), 3);
+
+        // mixing typed and untyped placeholders
+        let g = partial!(foo => _: i32, 2, _);
+        assert_eq!(g(1, false), -1);
+
+        // an annotated closure can be passed directly into a generic context
+        // like an iterator adapter, without a separately typed binding
+        fn add(a: i32, b: i32) -> i32 {
+            a + b
+        }
+        let added: [i32; 3] = [1, 2, 3].map(partial!(add => _: i32, 10));
+        assert_eq!(added, [11, 12, 13]);
+    }
 }
 
 // moving a !Copy type forces FnOnce
@@ -247,4 +710,38 @@ fn syntax_check() {
     partial!(move foo => 2, _, num, {stringify!(boo); 2}, b.0, five(), s.clone().0);
     let s = a;
     partial!(move foo => 2, _, num, {stringify!(boo); 2}, b.0, five(), s.0);
+
+    // eager capture, with and without trailing comma, mixed with other forms
+    partial!(foo => =2, _, num, ={stringify!(boo); 2}, b.0, five(), _);
+    partial!(foo => =2, _, num, ={stringify!(boo); 2}, b.0, five(), _,);
+    partial!(foo => =2, _, =num, ={stringify!(boo); 2}, b.0, five(), =NoCopy);
+
+    // numbered placeholders, with and without trailing comma
+    fn bar(a: u8, b: u8, c: u8) -> u8 {
+        a + b + c
+    }
+    partial!(bar => _2, _0, _1);
+    partial!(bar => _2, _0, _1,);
+    partial!(bar => _0, _0, num);
+
+    // method-receiver call, with an ident and a parenthesized receiver, `;` and
+    // trailing comma, plain and `move`
+    struct Adder(u8);
+    impl Adder {
+        fn add(&self, n: u8) -> u8 {
+            self.0 + n
+        }
+    }
+    let adder = Adder(1);
+
+    partial!(adder.add, 2);
+    partial!(adder.add => 2,);
+    partial!((adder).add ; 2);
+    partial!(move adder.add, 2);
+
+    // typed placeholders, with and without trailing comma, mixed with untyped `_`
+    // and `move`
+    partial!(foo => 2, _: u8, num, {stringify!(boo); 2}, b.0, five(), _);
+    partial!(foo => 2, _: u8, num, {stringify!(boo); 2}, b.0, five(), _: NoCopy,);
+    partial!(move foo => 2, _: u8, num, {stringify!(boo); 2}, b.0, five(), _: NoCopy);
 }